@@ -45,6 +45,38 @@ pub fn write_api_endpoints_to_json_file() -> std::io::Result<()> {
     Ok(())
 }
 
+/// Render 16 raw bytes as the canonical 36-character hyphenated UUID string,
+/// inserting the four hyphens so the hex digits split into blocks of
+/// 8, 4, 4, 4 and 12. This is the single place the 8-4-4-4-12 layout is
+/// encoded; the generators and `format_uuid` all defer to it.
+fn hyphenate(bytes: &[u8; 16]) -> String {
+    let mut uuid = String::new();
+
+    for n in bytes {
+        // Add four hyphen "-" characters to obtain blocks of 8, 4, 4, 4 and
+        // 12 hex digits
+        if uuid.len() == 8 {
+            uuid.push('-');
+        }
+
+        if uuid.len() == 8 + 4 + 1 {
+            uuid.push('-');
+        }
+
+        if uuid.len() == 8 + 4 + 4 + 2 {
+            uuid.push('-');
+        }
+
+        if uuid.len() == 8 + 4 + 4 + 4 + 3 {
+            uuid.push('-');
+        }
+
+        uuid.push_str(&format!("{:02x}", n));
+    }
+
+    uuid
+}
+
 /// The procedure to generate a version 4 UUID is as follows:
 ///
 /// >> In RFC Technical Terms:
@@ -75,11 +107,11 @@ pub fn write_api_endpoints_to_json_file() -> std::io::Result<()> {
 /// 5. Output the resulting 36-character string
 ///     "XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX"
 pub fn generate_v4_uuid() -> String {
-    let mut uuid_v4 = String::new();
+    let mut bytes = [0u8; 16];
 
     // 1. Generate 16 random bytes (=128 bits)
     let mut rng = rand::thread_rng();
-    for x in 0..16 {
+    for (x, byte) in bytes.iter_mut().enumerate() {
         let mut n = rng.gen::<u8>();
 
         // 2a. set the four most significant bits of the 7th byte to 0100'B, so
@@ -98,39 +130,276 @@ pub fn generate_v4_uuid() -> String {
             n = (n & first_and) | second_or;
         }
 
-        // 4. Add four hyphen "-" characters to obtain blocks of 8, 4, 4, 4 and
-        // 12 hex digits
-        if uuid_v4.len() == 8 {
-            uuid_v4.push('-');
+        *byte = n;
+    }
+
+    // 3-5. Encode the adjusted bytes as 32 hexadecimal digits, hyphenate them
+    // into the 8-4-4-4-12 layout, and output the resulting 36-character string
+    // "XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX"
+    hyphenate(&bytes)
+}
+
+/// The procedure to generate a version 7 UUID is as follows:
+///
+/// >> In RFC Technical Terms:
+/// >> https://www.rfc-editor.org/rfc/rfc9562#name-uuid-version-7
+///
+/// Version 7 UUIDs lead with a 48-bit big-endian Unix timestamp in
+/// milliseconds, which makes the textual form lexicographically sortable in
+/// time order. This keeps inserts into dustdb locality-friendly instead of
+/// scattering random v4 keys across the index.
+///
+/// 1. Take the current Unix time in milliseconds and fill bytes 0-5
+///     high-to-low (big-endian).
+/// 2. Generate random values for the remaining bytes, then adjust certain bits:
+///     a. set the high nibble of the 7th byte to 0111'B, so the high nibble is
+///         "7" (version 7), leaving its low nibble random.
+///     b. set the two most significant bits of the 9th byte to 10'B (RFC 4122
+///         variant), leaving the rest random.
+/// 3. Encode the adjusted bytes as 32 hexadecimal digits
+/// 4. Add four hyphen "-" characters to obtain blocks of 8, 4, 4, 4 and 12 hex
+///     digits
+/// 5. Output the resulting 36-character string
+///     "XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX"
+pub fn generate_v7_uuid() -> String {
+    let mut bytes = [0u8; 16];
+
+    // 1. Take the current Unix time in milliseconds and fill bytes 0-5
+    // high-to-low (big-endian).
+    let millis = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis();
+
+    let mut rng = rand::thread_rng();
+    for (x, byte) in bytes.iter_mut().enumerate() {
+        let mut n = if x < 6 {
+            // The 48-bit timestamp occupies bytes 0-5, most significant byte
+            // first. Byte 0 holds bits 47-40, byte 5 holds bits 7-0.
+            ((millis >> (8 * (5 - x))) & 0xff) as u8
+        } else {
+            rng.gen::<u8>()
+        };
+
+        // 2a. set the high nibble of the 7th byte to 0111'B, so the high nibble
+        // is "7" (version 7), leaving its low nibble random.
+        if x == 6 {
+            let first_and = 0b00001111u8;
+            let second_or = 0b01110000u8;
+            n = (n & first_and) | second_or;
         }
 
-        if uuid_v4.len() == 8 + 4 + 1 {
-            uuid_v4.push('-');
+        // 2b. set the two most significant bits of the 9th byte to 10'B (RFC
+        // 4122 variant), leaving the rest random.
+        if x == 8 {
+            let first_and = 0b00111111u8;
+            let second_or = 0b10000000u8;
+            n = (n & first_and) | second_or;
         }
 
-        if uuid_v4.len() == 8 + 4 + 4 + 2 {
-            uuid_v4.push('-');
+        *byte = n;
+    }
+
+    // 3-5. Encode the adjusted bytes as 32 hexadecimal digits, hyphenate them
+    // into the 8-4-4-4-12 layout, and output the resulting 36-character string
+    // "XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX"
+    hyphenate(&bytes)
+}
+
+/// The textual forms a UUID can be rendered in. Generation produces raw bytes;
+/// presentation is chosen separately so dustweb can, for example, serialize IDs
+/// compactly in URLs (`Simple`) while still supporting canonical display.
+pub enum UuidFormat {
+    /// The canonical 8-4-4-4-12 form, e.g. "xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx".
+    Hyphenated,
+    /// 32 hexadecimal digits with no hyphens.
+    Simple,
+    /// The hyphenated form prefixed with "urn:uuid:".
+    Urn,
+    /// The hyphenated form wrapped in "{}".
+    Braced,
+}
+
+/// Render raw UUID bytes into the requested textual form. Keeping this separate
+/// from the generators means the same 16 bytes can be displayed several ways.
+pub fn format_uuid(bytes: &[u8; 16], fmt: UuidFormat) -> String {
+    match fmt {
+        UuidFormat::Simple => bytes.iter().map(|b| format!("{:02x}", b)).collect(),
+        UuidFormat::Hyphenated => hyphenate(bytes),
+        UuidFormat::Urn => format!("urn:uuid:{}", hyphenate(bytes)),
+        UuidFormat::Braced => format!("{{{}}}", hyphenate(bytes)),
+    }
+}
+
+/// Parse a 36-character hyphenated UUID string back into its 16 raw bytes.
+///
+/// This is the inverse of `generate_v4_uuid` (and the other generators): it
+/// strips the four hyphens at their fixed offsets (8, 13, 18, 23), rejects any
+/// string of the wrong length or with a misplaced hyphen, and decodes the
+/// remaining 32 hexadecimal digits to 16 bytes using the same `from_str_radix`
+/// approach as `decode_hex_to_utf8`. Any malformed digit yields
+/// `ErrorKind::InvalidInput`.
+pub fn parse_uuid(s: &str) -> Result<[u8; 16], io::Error> {
+    let invalid = |input: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Could not parse: \"{}\", invalid input", input),
+        )
+    };
+
+    // The canonical form is 36 characters with hyphens at fixed offsets.
+    if s.len() != 36 {
+        return Err(invalid(s));
+    }
+
+    let bytes = s.as_bytes();
+    for offset in [8, 13, 18, 23] {
+        if bytes[offset] != b'-' {
+            return Err(invalid(s));
         }
+    }
+
+    // Drop the hyphens, leaving the hex digits to decode pairwise. The four
+    // fixed-offset checks above only guarantee hyphens are present there, not
+    // that there are no extras elsewhere; a stray hyphen (with a hex digit
+    // dropped to keep the length at 36) would leave an odd-length string and
+    // panic the `hex[i..i + 2]` slice below, so require exactly 32 digits.
+    // Each must be an ASCII hex digit: `from_str_radix` alone would accept a
+    // leading '+' in a pair, and a multi-byte character would split the slice.
+    let hex: String = s.chars().filter(|&c| c != '-').collect();
+    if hex.len() != 32 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid(s));
+    }
+
+    let v: Result<Vec<u8>, ParseIntError> = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+        .collect();
 
-        if uuid_v4.len() == 8 + 4 + 4 + 4 + 3 {
-            uuid_v4.push('-');
+    match v {
+        Ok(v_as_bytes) => {
+            let mut out = [0u8; 16];
+            out.copy_from_slice(&v_as_bytes);
+            Ok(out)
         }
+        Err(_) => Err(invalid(s)),
+    }
+}
+
+/// Return the UUID version, i.e. the high nibble of byte 6 (4 for v4, 7 for v7,
+/// 5 for v5, ...). Callers use this to confirm they received a real RFC 4122
+/// value rather than an arbitrary hex blob before trusting it as a key.
+pub fn get_version(bytes: &[u8; 16]) -> u8 {
+    bytes[6] >> 4
+}
 
-        uuid_v4.push_str(&format!("{:02x}", n));
+/// Return the RFC 4122 variant, i.e. the two most significant bits of byte 8.
+/// A value of `0b10` (2) denotes the standard variant emitted by this crate's
+/// generators.
+pub fn get_variant(bytes: &[u8; 16]) -> u8 {
+    bytes[8] >> 6
+}
+
+/// Predefined namespace UUIDs from RFC 4122 Appendix C, as raw bytes, for use
+/// with `generate_v5_uuid`.
+pub const NAMESPACE_DNS: [u8; 16] = [
+    0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8,
+];
+pub const NAMESPACE_URL: [u8; 16] = [
+    0x6b, 0xa7, 0xb8, 0x11, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8,
+];
+pub const NAMESPACE_OID: [u8; 16] = [
+    0x6b, 0xa7, 0xb8, 0x12, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8,
+];
+pub const NAMESPACE_X500: [u8; 16] = [
+    0x6b, 0xa7, 0xb8, 0x14, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8,
+];
 
-        // println!(
-        //     "Index [{}]:\t{:#010b}\t(Byte #{})\t=>\t{}\t=>\t{:02x}",
-        //     x,
-        //     n,
-        //     x + 1,
-        //     n,
-        //     n
-        // );
+/// Compute the SHA-1 digest (20 bytes) of a byte slice, per FIPS 180-4. Kept
+/// internal because v5 UUIDs are its only caller; the crate avoids pulling in a
+/// dependency for a single fixed-size hash.
+fn sha1_digest(message: &[u8]) -> [u8; 20] {
+    let mut h: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+    // Pad: append 0x80, then zeros, then the 64-bit big-endian bit length.
+    let mut data = message.to_vec();
+    let bit_len = (message.len() as u64) * 8;
+    data.push(0x80);
+    while data.len() % 64 != 56 {
+        data.push(0);
     }
+    data.extend_from_slice(&bit_len.to_be_bytes());
 
-    // 5. Output the resulting 36-character string
-    // "XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX"
-    uuid_v4
+    for chunk in data.chunks_exact(64) {
+        let mut w = [0u32; 80];
+        for (i, word) in chunk.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let (mut a, mut b, mut c, mut d, mut e) = (h[0], h[1], h[2], h[3], h[4]);
+        for (i, &word) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | ((!b) & d), 0x5A827999),
+                20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+                _ => (b ^ c ^ d, 0xCA62C1D6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+
+        h[0] = h[0].wrapping_add(a);
+        h[1] = h[1].wrapping_add(b);
+        h[2] = h[2].wrapping_add(c);
+        h[3] = h[3].wrapping_add(d);
+        h[4] = h[4].wrapping_add(e);
+    }
+
+    let mut out = [0u8; 20];
+    for (i, word) in h.iter().enumerate() {
+        out[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+    }
+    out
+}
+
+/// Generate a deterministic, name-based (version 5, SHA-1) UUID. The 16
+/// namespace bytes are concatenated with the name bytes, hashed with SHA-1, and
+/// the first 16 bytes of the digest become the UUID after setting the version
+/// (5) and RFC 4122 variant bits. The same name under the same namespace always
+/// yields the identical string, which makes it suitable for idempotent resource
+/// creation (e.g. deriving a stable user ID from an email).
+pub fn generate_v5_uuid(namespace: &[u8; 16], name: &[u8]) -> String {
+    let mut message = Vec::with_capacity(16 + name.len());
+    message.extend_from_slice(namespace);
+    message.extend_from_slice(name);
+
+    let digest = sha1_digest(&message);
+
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[0..16]);
+
+    // Overwrite the high nibble of byte 6 with 0101'B (version 5).
+    bytes[6] = (bytes[6] & 0b00001111) | 0b01010000;
+    // Overwrite the two most significant bits of byte 8 with 10'B (variant).
+    bytes[8] = (bytes[8] & 0b00111111) | 0b10000000;
+
+    format_uuid(&bytes, UuidFormat::Hyphenated)
+}
+
+pub fn encode_utf8_to_hex(text: &str) -> String {
+    text.as_bytes().iter().map(|b| format!("{:02x}", b)).collect()
 }
 
 pub fn decode_hex_to_utf8(text_to_decode: &str) -> Result<String, io::Error> {
@@ -158,7 +427,9 @@ mod tests {
     use regex::Regex;
 
     use crate::{
-        decode_hex_to_utf8, generate_v4_uuid, get_env_var, write_api_endpoints_to_json_file,
+        decode_hex_to_utf8, encode_utf8_to_hex, format_uuid, generate_v4_uuid, generate_v5_uuid,
+        generate_v7_uuid, get_env_var, get_variant, get_version, parse_uuid,
+        write_api_endpoints_to_json_file, UuidFormat, NAMESPACE_DNS,
     };
     use std::{io, path::Path};
 
@@ -191,6 +462,102 @@ mod tests {
         assert!(re.is_match(&generate_v4_uuid()));
     }
 
+    #[test]
+    fn test_generate_v7_uuid() {
+        let re = Regex::new(
+            r"^[0-9a-fA-F]{8}\-[0-9a-fA-F]{4}\-7[0-9a-fA-F]{3}\-[89abAB][0-9a-fA-F]{3}\-[0-9a-fA-F]{12}$",
+        )
+        .unwrap();
+        assert!(re.is_match(&generate_v7_uuid()));
+    }
+
+    #[test]
+    fn test_generate_v7_uuid_sorts_in_time_order() {
+        let earlier = generate_v7_uuid();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let later = generate_v7_uuid();
+        assert!(earlier < later);
+    }
+
+    #[test]
+    fn test_parse_uuid_round_trips_v4() {
+        let uuid = generate_v4_uuid();
+        let bytes = parse_uuid(&uuid).unwrap();
+        assert_eq!(4, get_version(&bytes));
+        assert_eq!(0b10, get_variant(&bytes));
+    }
+
+    #[test]
+    fn test_parse_uuid_round_trips_v7() {
+        let uuid = generate_v7_uuid();
+        let bytes = parse_uuid(&uuid).unwrap();
+        assert_eq!(7, get_version(&bytes));
+        assert_eq!(0b10, get_variant(&bytes));
+    }
+
+    #[test]
+    fn test_parse_uuid_should_error() {
+        // Wrong length.
+        assert_eq!(
+            Err(io::ErrorKind::InvalidInput),
+            parse_uuid("deadbeef").map_err(|e| e.kind())
+        );
+        // Misplaced hyphens.
+        assert_eq!(
+            Err(io::ErrorKind::InvalidInput),
+            parse_uuid("000000000000-0000-0000-0000-00000000").map_err(|e| e.kind())
+        );
+        // Malformed hex digit.
+        assert_eq!(
+            Err(io::ErrorKind::InvalidInput),
+            parse_uuid("zzzzzzzz-0000-0000-0000-000000000000").map_err(|e| e.kind())
+        );
+        // 36 chars with hyphens at the right offsets but an extra hyphen
+        // elsewhere: must be rejected, not panic on the odd-length hex slice.
+        assert_eq!(
+            Err(io::ErrorKind::InvalidInput),
+            parse_uuid("00000-00-0000-0000-0000-000000000000").map_err(|e| e.kind())
+        );
+    }
+
+    #[test]
+    fn test_format_uuid() {
+        let bytes: [u8; 16] = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee, 0xff,
+        ];
+        assert_eq!(
+            "00112233445566778899aabbccddeeff",
+            format_uuid(&bytes, UuidFormat::Simple)
+        );
+        assert_eq!(
+            "00112233-4455-6677-8899-aabbccddeeff",
+            format_uuid(&bytes, UuidFormat::Hyphenated)
+        );
+        assert_eq!(
+            "urn:uuid:00112233-4455-6677-8899-aabbccddeeff",
+            format_uuid(&bytes, UuidFormat::Urn)
+        );
+        assert_eq!(
+            "{00112233-4455-6677-8899-aabbccddeeff}",
+            format_uuid(&bytes, UuidFormat::Braced)
+        );
+    }
+
+    #[test]
+    fn test_generate_v5_uuid_is_deterministic() {
+        // Known RFC 4122 test vector: v5 of the DNS namespace over "python.org".
+        assert_eq!(
+            "886313e1-3b8a-5372-9b90-0c9aee199e5d",
+            generate_v5_uuid(&NAMESPACE_DNS, b"python.org")
+        );
+        // Same inputs must always produce the same output.
+        assert_eq!(
+            generate_v5_uuid(&NAMESPACE_DNS, b"python.org"),
+            generate_v5_uuid(&NAMESPACE_DNS, b"python.org")
+        );
+    }
+
     #[test]
     fn test_decode_hex_to_utf8() {
         assert_eq!("z", decode_hex_to_utf8("7A").unwrap());
@@ -204,6 +571,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encode_utf8_to_hex() {
+        assert_eq!("7a", encode_utf8_to_hex("z"));
+        assert_eq!(
+            "7b226e616d65223a224a6f686e222c2022616765223a33302c2022636172223a6e756c6c7d",
+            encode_utf8_to_hex("{\"name\":\"John\", \"age\":30, \"car\":null}")
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_round_trip() {
+        let json_body = "{\"name\":\"John\", \"age\":30, \"car\":null}";
+        assert_eq!(
+            json_body,
+            decode_hex_to_utf8(&encode_utf8_to_hex(json_body)).unwrap()
+        );
+    }
+
     #[test]
     fn test_decode_hex_to_utf8_should_error() {
         let result = decode_hex_to_utf8("testy").map_err(|e| e.kind());